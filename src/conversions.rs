@@ -0,0 +1,24 @@
+//! Conversions between Bevy's and Lyon's vector types.
+
+use bevy::math::Vec2;
+use lyon_tessellation::math::{Point, Vector};
+
+pub trait ToLyonPoint {
+    fn to_lyon_point(&self) -> Point;
+}
+
+impl ToLyonPoint for Vec2 {
+    fn to_lyon_point(&self) -> Point {
+        Point::new(self.x(), self.y())
+    }
+}
+
+pub trait ToLyonVector {
+    fn to_lyon_vector(&self) -> Vector;
+}
+
+impl ToLyonVector for Vec2 {
+    fn to_lyon_vector(&self) -> Vector {
+        Vector::new(self.x(), self.y())
+    }
+}
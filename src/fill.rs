@@ -0,0 +1,182 @@
+//! Fill styles: solid colors and gradients.
+
+use bevy::render::color::Color;
+use lyon_tessellation::math::Point;
+
+/// A single color stop within a gradient, positioned along the gradient's
+/// axis at `offset` (`0.0` is the start of the axis, `1.0` its end). The
+/// offset is used as given; only the sampled position is clamped to
+/// `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl GradientStop {
+    pub fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// How a shape should be colored.
+///
+/// `Solid` assigns the same color to every vertex, matching the crate's
+/// previous behavior. The gradient variants compute a color per vertex from
+/// its tessellated position, so the resulting mesh carries smoothly
+/// interpolated vertex colors.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    Solid(Color),
+    LinearGradient {
+        start: Point,
+        end: Point,
+        stops: Vec<GradientStop>,
+    },
+    RadialGradient {
+        center: Point,
+        radius: f32,
+        stops: Vec<GradientStop>,
+    },
+}
+
+impl Fill {
+    /// Resolves the color at `point`, projecting it against the gradient's
+    /// parameters and interpolating between stops.
+    pub fn color_at(&self, point: Point) -> Color {
+        match self {
+            Self::Solid(color) => *color,
+            Self::LinearGradient { start, end, stops } => {
+                let axis = *end - *start;
+                let length_squared = axis.square_length();
+                let t = if length_squared > 0.0 {
+                    (point - *start).dot(axis) / length_squared
+                } else {
+                    0.0
+                };
+                sample_stops(stops, t.max(0.0).min(1.0))
+            }
+            Self::RadialGradient {
+                center,
+                radius,
+                stops,
+            } => {
+                let t = if *radius > 0.0 {
+                    (point - *center).length() / radius
+                } else {
+                    0.0
+                };
+                sample_stops(stops, t.max(0.0).min(1.0))
+            }
+        }
+    }
+}
+
+/// Linearly interpolates between the two stops surrounding `t`.
+fn sample_stops(stops: &[GradientStop], t: f32) -> Color {
+    match stops {
+        [] => Color::WHITE,
+        [only] => only.color,
+        _ => {
+            if t <= stops[0].offset {
+                return stops[0].color;
+            }
+            for pair in stops.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                if t <= b.offset {
+                    let span = (b.offset - a.offset).max(f32::EPSILON);
+                    let local_t = ((t - a.offset) / span).max(0.0).min(1.0);
+                    return lerp_color(a.color, b.color, local_t);
+                }
+            }
+            stops[stops.len() - 1].color
+        }
+    }
+}
+
+fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    Color::rgba(
+        a.r() + (b.r() - a.r()) * t,
+        a.g() + (b.g() - a.g()) * t,
+        a.b() + (b.b() - a.b()) * t,
+        a.a() + (b.a() - a.a()) * t,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stops() -> Vec<GradientStop> {
+        vec![
+            GradientStop::new(0.0, Color::RED),
+            GradientStop::new(0.5, Color::GREEN),
+            GradientStop::new(1.0, Color::BLUE),
+        ]
+    }
+
+    #[test]
+    fn sample_stops_clamps_before_the_first_stop() {
+        assert_eq!(sample_stops(&stops(), -1.0), Color::RED);
+    }
+
+    #[test]
+    fn sample_stops_clamps_after_the_last_stop() {
+        assert_eq!(sample_stops(&stops(), 2.0), Color::BLUE);
+    }
+
+    #[test]
+    fn sample_stops_returns_exact_stop_colors() {
+        assert_eq!(sample_stops(&stops(), 0.5), Color::GREEN);
+    }
+
+    #[test]
+    fn sample_stops_interpolates_between_stops() {
+        let color = sample_stops(&stops(), 0.25);
+        assert_eq!(color, lerp_color(Color::RED, Color::GREEN, 0.5));
+    }
+
+    #[test]
+    fn sample_stops_with_a_single_stop_is_constant() {
+        let stops = vec![GradientStop::new(0.5, Color::GREEN)];
+        assert_eq!(sample_stops(&stops, 0.0), Color::GREEN);
+        assert_eq!(sample_stops(&stops, 1.0), Color::GREEN);
+    }
+
+    #[test]
+    fn sample_stops_with_no_stops_is_white() {
+        assert_eq!(sample_stops(&[], 0.5), Color::WHITE);
+    }
+
+    #[test]
+    fn color_at_follows_the_linear_gradient_axis() {
+        let fill = Fill::LinearGradient {
+            start: Point::new(0.0, 0.0),
+            end: Point::new(10.0, 0.0),
+            stops: stops(),
+        };
+        assert_eq!(fill.color_at(Point::new(0.0, 0.0)), Color::RED);
+        assert_eq!(fill.color_at(Point::new(5.0, 0.0)), Color::GREEN);
+        assert_eq!(fill.color_at(Point::new(10.0, 0.0)), Color::BLUE);
+        // Points off the axis are still projected onto it.
+        assert_eq!(fill.color_at(Point::new(5.0, 100.0)), Color::GREEN);
+    }
+
+    #[test]
+    fn color_at_follows_the_radial_gradient_distance() {
+        let fill = Fill::RadialGradient {
+            center: Point::new(0.0, 0.0),
+            radius: 10.0,
+            stops: stops(),
+        };
+        assert_eq!(fill.color_at(Point::new(0.0, 0.0)), Color::RED);
+        assert_eq!(fill.color_at(Point::new(5.0, 0.0)), Color::GREEN);
+        assert_eq!(fill.color_at(Point::new(0.0, 10.0)), Color::BLUE);
+    }
+
+    #[test]
+    fn color_at_solid_ignores_the_point() {
+        let fill = Fill::Solid(Color::RED);
+        assert_eq!(fill.color_at(Point::new(123.0, -45.0)), Color::RED);
+    }
+}
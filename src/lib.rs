@@ -0,0 +1,218 @@
+//! `bevy_prototype_lyon` is a Bevy plugin for drawing vector shapes, built on
+//! top of the [`lyon`](https://github.com/nical/lyon) tessellation library.
+//!
+//! Enabling the `lyon_tess2` cargo feature additionally links in the
+//! `libtess2`-based tessellator from the `lyon_tess2` crate, selectable via
+//! [`TessellationMode::Tess2Fill`].
+
+pub mod conversions;
+pub mod fill;
+pub mod shapes;
+
+pub use fill::{Fill, GradientStop};
+
+use bevy::{
+    asset::{Assets, Handle},
+    ecs::ResMut,
+    render::mesh::{Indices, Mesh, VertexAttributeValues},
+    render::pipeline::PrimitiveTopology,
+    sprite::{entity::SpriteBundle, ColorMaterial},
+    transform::components::Transform,
+};
+use lyon_tessellation::{
+    self as tess,
+    math::Point,
+    path::Path,
+    BuffersBuilder, FillOptions, FillTessellator, StrokeOptions, StrokeTessellator, VertexBuffers,
+};
+
+pub mod prelude {
+    pub use crate::shapes;
+    pub use crate::{Fill, GradientStop, ShapeSprite, Tessellator, TessellationMode};
+}
+
+/// A single tessellated vertex, carrying its position and the color
+/// resolved for it by the shape's [`Fill`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vertex {
+    pub position: [f32; 2],
+    pub color: [f32; 4],
+}
+
+/// The raw output of the tessellation process, ready to be turned into a
+/// [`Mesh`].
+pub type Buffers = VertexBuffers<Vertex, u32>;
+
+/// Holds the tessellators used to turn paths into meshes.
+///
+/// A single [`Tessellator`] can be reused across many shapes.
+pub struct Tessellator {
+    pub fill: FillTessellator,
+    pub stroke: StrokeTessellator,
+    /// The `libtess2`-backed fill tessellator, used for
+    /// [`TessellationMode::Tess2Fill`]. Handles self-intersecting contours
+    /// that `lyon`'s own [`FillTessellator`] cannot.
+    #[cfg(feature = "lyon_tess2")]
+    pub tess2_fill: lyon_tess2::FillTessellator,
+}
+
+impl Tessellator {
+    pub fn new() -> Self {
+        Self {
+            fill: FillTessellator::new(),
+            stroke: StrokeTessellator::new(),
+            #[cfg(feature = "lyon_tess2")]
+            tess2_fill: lyon_tess2::FillTessellator::new(),
+        }
+    }
+}
+
+impl Default for Tessellator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Determines whether a shape is filled, stroked, and with what options.
+///
+/// `Fill` and `Stroke` wrap `lyon_tessellation`'s own options types directly, so
+/// every knob `lyon` exposes (line width, caps, joins, miter limit,
+/// tolerance, ...) is available without this crate needing to shadow it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TessellationMode {
+    Fill(FillOptions),
+    Stroke(StrokeOptions),
+    /// Fills using the `libtess2` backend (via the `lyon_tess2` crate)
+    /// instead of `lyon`'s default tessellator. Requires the `lyon_tess2`
+    /// cargo feature. Prefer this over [`TessellationMode::Fill`] for
+    /// self-intersecting contours (e.g. from [`shapes::SvgPathShape`] or a
+    /// hand-built [`shapes::Polygon`]), where `options.fill_rule` set to
+    /// `NonZero` renders correctly but `lyon`'s tessellator produces
+    /// artifacts.
+    #[cfg(feature = "lyon_tess2")]
+    Tess2Fill(lyon_tess2::FillOptions),
+}
+
+/// Implemented by every shape in the [`shapes`] module so it can be turned
+/// into a [`SpriteBundle`].
+pub trait ShapeSprite {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        fill: Fill,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle;
+
+    /// Tessellates `path` according to `mode`, appending the resulting
+    /// vertices and indices to `buffers`. Each emitted vertex's color is
+    /// resolved from `fill` against that vertex's tessellated position.
+    fn tessellate(
+        &self,
+        path: &Path,
+        fill: &Fill,
+        buffers: &mut Buffers,
+        mode: TessellationMode,
+        tessellator: &mut Tessellator,
+    ) {
+        match mode {
+            TessellationMode::Fill(options) => {
+                tessellator
+                    .fill
+                    .tessellate_path(path, &options, &mut BuffersBuilder::new(buffers, VertexConstructor { fill }))
+                    .expect("failed to tessellate fill path");
+            }
+            TessellationMode::Stroke(options) => {
+                tessellator
+                    .stroke
+                    .tessellate_path(path, &options, &mut BuffersBuilder::new(buffers, VertexConstructor { fill }))
+                    .expect("failed to tessellate stroke path");
+            }
+            #[cfg(feature = "lyon_tess2")]
+            TessellationMode::Tess2Fill(options) => {
+                tessellator
+                    .tess2_fill
+                    .tessellate_path(
+                        path,
+                        &options,
+                        &mut lyon_tess2::geometry_builder::BuffersBuilder::new(buffers, VertexConstructor { fill }),
+                    )
+                    .expect("failed to tessellate path with the lyon_tess2 backend");
+            }
+        }
+    }
+}
+
+struct VertexConstructor<'a> {
+    fill: &'a Fill,
+}
+
+impl<'a> tess::FillVertexConstructor<Vertex> for VertexConstructor<'a> {
+    fn new_vertex(&mut self, vertex: tess::FillVertex) -> Vertex {
+        let point = vertex.position();
+        let color = self.fill.color_at(point);
+        Vertex {
+            position: point.to_array(),
+            color: [color.r(), color.g(), color.b(), color.a()],
+        }
+    }
+}
+
+impl<'a> tess::StrokeVertexConstructor<Vertex> for VertexConstructor<'a> {
+    fn new_vertex(&mut self, vertex: tess::StrokeVertex) -> Vertex {
+        let point = vertex.position();
+        let color = self.fill.color_at(point);
+        Vertex {
+            position: point.to_array(),
+            color: [color.r(), color.g(), color.b(), color.a()],
+        }
+    }
+}
+
+/// `lyon_tess2` doesn't mirror `lyon_tessellation`'s own vertex constructor
+/// traits — it has its own `geometry_builder` module with a position-only
+/// `BasicVertexConstructor`, since libtess2 doesn't expose the extra fill
+/// attributes `lyon`'s tessellator does.
+#[cfg(feature = "lyon_tess2")]
+impl<'a> lyon_tess2::geometry_builder::BasicVertexConstructor<Vertex> for VertexConstructor<'a> {
+    fn new_vertex(&mut self, point: Point) -> Vertex {
+        let color = self.fill.color_at(point);
+        Vertex {
+            position: point.to_array(),
+            color: [color.r(), color.g(), color.b(), color.a()],
+        }
+    }
+}
+
+/// Builds the [`Mesh`] for `buffers` and wraps it in a [`SpriteBundle`].
+fn create_sprite(
+    material: Handle<ColorMaterial>,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    buffers: Buffers,
+    transform: Transform,
+) -> SpriteBundle {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+
+    let positions = buffers
+        .vertices
+        .iter()
+        .map(|v| [v.position[0], v.position[1], 0.0])
+        .collect::<Vec<[f32; 3]>>();
+    let colors = buffers
+        .vertices
+        .iter()
+        .map(|v| v.color)
+        .collect::<Vec<[f32; 4]>>();
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, VertexAttributeValues::from(positions));
+    mesh.set_attribute(Mesh::ATTRIBUTE_COLOR, VertexAttributeValues::from(colors));
+    mesh.set_indices(Some(Indices::U32(buffers.indices)));
+
+    SpriteBundle {
+        mesh: meshes.add(mesh),
+        material,
+        transform,
+        ..Default::default()
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     conversions::{ToLyonPoint, ToLyonVector},
-    create_sprite, Buffers, ShapeSprite, TessellationMode, Tessellator,
+    create_sprite, Buffers, Fill, ShapeSprite, TessellationMode, Tessellator,
 };
 use bevy::{
     asset::{Assets, Handle},
@@ -12,10 +12,15 @@ use bevy::{
     sprite::{entity::SpriteBundle, ColorMaterial},
     transform::components::Transform,
 };
+use lyon_svg::path_utils::{build_path, ParseError};
 use lyon_tessellation::{
     math::{Angle, Point, Rect, Size},
-    path::{path::Builder, traits::PathBuilder, Polygon as LyonPolygon, Winding},
+    path::{
+        builder::BorderRadii as LyonBorderRadii, path::Builder, traits::PathBuilder, Path,
+        Polygon as LyonPolygon, Winding,
+    },
 };
+use std::f32::consts::PI;
 
 /// Defines where the origin, or pivot of the `Rectangle` should be positioned.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -54,6 +59,7 @@ impl ShapeSprite for Rectangle {
     fn generate_sprite(
         &self,
         material: Handle<ColorMaterial>,
+        fill: Fill,
         meshes: &mut ResMut<Assets<Mesh>>,
         tessellator: &mut Tessellator,
         mode: TessellationMode,
@@ -77,7 +83,109 @@ impl ShapeSprite for Rectangle {
         );
         let path = path_builder.build();
 
-        self.tessellate(&path, &mut buffers, mode, tessellator);
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
+
+        create_sprite(material, meshes, buffers, transform)
+    }
+}
+
+/// Specifies the corner radii of a [`RoundedRectangle`], either uniformly or
+/// per corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderRadii {
+    /// The same radius applied to all four corners.
+    Single(f32),
+    /// Independent radii for `[top_left, top_right, bottom_right,
+    /// bottom_left]`.
+    Individual([f32; 4]),
+}
+
+impl BorderRadii {
+    /// Resolves into `[top_left, top_right, bottom_right, bottom_left]`
+    /// radii, clamping each one to at most half of the shorter side so the
+    /// corners never overlap.
+    fn resolve(&self, width: f32, height: f32) -> [f32; 4] {
+        let radii = match *self {
+            Self::Single(radius) => [radius; 4],
+            Self::Individual(radii) => radii,
+        };
+
+        let max_radius = width.min(height) / 2.0;
+        let mut clamped = [0.0; 4];
+        for (i, radius) in radii.iter().enumerate() {
+            clamped[i] = radius.max(0.0).min(max_radius);
+        }
+        clamped
+    }
+}
+
+impl Default for BorderRadii {
+    fn default() -> Self {
+        Self::Single(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedRectangle {
+    pub width: f32,
+    pub height: f32,
+    pub border_radius: BorderRadii,
+    pub origin: RectangleOrigin,
+}
+
+impl Default for RoundedRectangle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            height: 1.0,
+            border_radius: BorderRadii::default(),
+            origin: RectangleOrigin::default(),
+        }
+    }
+}
+
+impl ShapeSprite for RoundedRectangle {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        fill: Fill,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut buffers = Buffers::new();
+
+        use RectangleOrigin::*;
+        let origin = match self.origin {
+            Center => Point::new(-self.width / 2.0, -self.height / 2.0),
+            BottomLeft => Point::new(0.0, 0.0),
+            BottomRight => Point::new(-self.width, 0.0),
+            TopRight => Point::new(-self.width, -self.height),
+            TopLeft => Point::new(0.0, -self.height),
+        };
+
+        let [top_left, top_right, bottom_right, bottom_left] =
+            self.border_radius.resolve(self.width, self.height);
+
+        // Lyon names corners with y pointing down, so its "top" corners sit
+        // at the minimum y, which is the bottom edge with Bevy's y-up axis.
+        let radii = LyonBorderRadii {
+            top_left: bottom_left,
+            top_right: bottom_right,
+            bottom_left: top_left,
+            bottom_right: top_right,
+        };
+
+        let mut path_builder = Builder::new();
+        path_builder.add_rounded_rectangle(
+            &Rect::new(origin, Size::new(self.width, self.height)),
+            &radii,
+            Winding::Positive,
+        );
+        let path = path_builder.build();
+
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
 
         create_sprite(material, meshes, buffers, transform)
     }
@@ -105,6 +213,7 @@ impl ShapeSprite for Circle {
     fn generate_sprite(
         &self,
         material: Handle<ColorMaterial>,
+        fill: Fill,
         meshes: &mut ResMut<Assets<Mesh>>,
         tessellator: &mut Tessellator,
         mode: TessellationMode,
@@ -116,7 +225,7 @@ impl ShapeSprite for Circle {
         path_builder.add_circle(self.center.to_lyon_point(), self.radius, Winding::Positive);
         let path = path_builder.build();
 
-        self.tessellate(&path, &mut buffers, mode, tessellator);
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
 
         create_sprite(material, meshes, buffers, transform)
     }
@@ -143,6 +252,7 @@ impl ShapeSprite for Ellipse {
     fn generate_sprite(
         &self,
         material: Handle<ColorMaterial>,
+        fill: Fill,
         meshes: &mut ResMut<Assets<Mesh>>,
         tessellator: &mut Tessellator,
         mode: TessellationMode,
@@ -159,7 +269,171 @@ impl ShapeSprite for Ellipse {
         );
         let path = path_builder.build();
 
-        self.tessellate(&path, &mut buffers, mode, tessellator);
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
+
+        create_sprite(material, meshes, buffers, transform)
+    }
+}
+
+/// Specifies how the size of a [`RegularPolygon`] should be interpreted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegularPolygonFeature {
+    /// The radius of the circle that circumscribes the polygon, passing
+    /// through each vertex.
+    Radius(f32),
+    /// The radius of the circle inscribed within the polygon, touching the
+    /// midpoint of each side. Also known as the apothem.
+    Apothem(f32),
+    /// The length of each side of the polygon.
+    SideLength(f32),
+}
+
+impl RegularPolygonFeature {
+    /// Converts this feature into the equivalent circumradius for a polygon
+    /// with the given number of `sides`.
+    pub fn radius(&self, sides: usize) -> f32 {
+        let n = sides as f32;
+        match *self {
+            Self::Radius(r) => r,
+            Self::Apothem(apothem) => apothem / (PI / n).cos(),
+            Self::SideLength(side_length) => side_length / (2.0 * (PI / n).sin()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegularPolygon {
+    /// The number of sides of the polygon. Clamped to a minimum of 3 when
+    /// the sprite is generated, since a polygon needs at least 3 sides.
+    pub sides: usize,
+    pub feature: RegularPolygonFeature,
+    /// The position of the center of the polygon, relative to the world
+    /// [`Translation`] of the [`SpriteBundle`].
+    pub center: Vec2,
+    /// The angle, in radians, of the first vertex. Defaults to pointing
+    /// straight up.
+    pub start_angle: f32,
+}
+
+impl Default for RegularPolygon {
+    fn default() -> Self {
+        Self {
+            sides: 3,
+            feature: RegularPolygonFeature::Radius(1.0),
+            center: Vec2::zero(),
+            start_angle: PI / 2.0,
+        }
+    }
+}
+
+impl ShapeSprite for RegularPolygon {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        fill: Fill,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut buffers = Buffers::new();
+
+        let sides = self.sides.max(3);
+        let radius = self.feature.radius(sides);
+        let points = (0..sides)
+            .map(|i| {
+                let angle = self.start_angle + i as f32 * 2.0 * PI / sides as f32;
+                Point::new(
+                    self.center.x() + radius * angle.cos(),
+                    self.center.y() + radius * angle.sin(),
+                )
+            })
+            .collect::<Vec<Point>>();
+        let polygon = LyonPolygon {
+            points: points.as_slice(),
+            closed: true,
+        };
+
+        let mut path_builder = Builder::new();
+        path_builder.add_polygon(polygon);
+        let path = path_builder.build();
+
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
+
+        create_sprite(material, meshes, buffers, transform)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Star {
+    /// The number of points of the star. Clamped to a minimum of 2 when the
+    /// sprite is generated, since a star needs at least 2 points to
+    /// alternate between inner and outer vertices.
+    pub points: usize,
+    /// The distance from the center to the inner vertices, between each
+    /// point.
+    pub inner_radius: f32,
+    /// The distance from the center to the outer vertices, at the tip of
+    /// each point.
+    pub outer_radius: f32,
+    /// The position of the center of the star, relative to the world
+    /// [`Translation`] of the [`SpriteBundle`].
+    pub center: Vec2,
+    /// The angle, in radians, of the first outer vertex. Defaults to
+    /// pointing straight up.
+    pub start_angle: f32,
+}
+
+impl Default for Star {
+    fn default() -> Self {
+        Self {
+            points: 5,
+            inner_radius: 0.5,
+            outer_radius: 1.0,
+            center: Vec2::zero(),
+            start_angle: PI / 2.0,
+        }
+    }
+}
+
+impl ShapeSprite for Star {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        fill: Fill,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut buffers = Buffers::new();
+
+        let points = self.points.max(2);
+        let step = PI / points as f32;
+        let vertices = (0..points * 2)
+            .map(|i| {
+                let radius = if i % 2 == 0 {
+                    self.outer_radius
+                } else {
+                    self.inner_radius
+                };
+                let angle = self.start_angle + i as f32 * step;
+                Point::new(
+                    self.center.x() + radius * angle.cos(),
+                    self.center.y() + radius * angle.sin(),
+                )
+            })
+            .collect::<Vec<Point>>();
+        let polygon = LyonPolygon {
+            points: vertices.as_slice(),
+            closed: true,
+        };
+
+        let mut path_builder = Builder::new();
+        path_builder.add_polygon(polygon);
+        let path = path_builder.build();
+
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
 
         create_sprite(material, meshes, buffers, transform)
     }
@@ -184,6 +458,7 @@ impl ShapeSprite for Polygon {
     fn generate_sprite(
         &self,
         material: Handle<ColorMaterial>,
+        fill: Fill,
         meshes: &mut ResMut<Assets<Mesh>>,
         tessellator: &mut Tessellator,
         mode: TessellationMode,
@@ -205,8 +480,134 @@ impl ShapeSprite for Polygon {
         path_builder.add_polygon(polygon);
         let path = path_builder.build();
 
-        self.tessellate(&path, &mut buffers, mode, tessellator);
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
+
+        create_sprite(material, meshes, buffers, transform)
+    }
+}
+
+/// A shape described by an SVG path data string (the contents of an SVG
+/// `<path>` element's `d` attribute), supporting the standard `M`/`L`/`C`/`Q`/
+/// `A`/`Z` commands.
+///
+/// This allows arbitrary vector art exported from design tools to be used
+/// directly as a shape, rather than being limited to the other primitives in
+/// this module.
+///
+/// Construct with [`SvgPathShape::new`], which parses `svg_path` eagerly so
+/// malformed input is rejected up front instead of panicking later inside an
+/// ECS system.
+#[derive(Debug, Clone)]
+pub struct SvgPathShape {
+    path: Path,
+}
+
+impl SvgPathShape {
+    /// Parses `svg_path` into a [`SvgPathShape`], failing if it isn't valid
+    /// SVG path data.
+    pub fn new(svg_path: &str) -> Result<Self, ParseError> {
+        let path = build_path(Builder::new().with_svg(), svg_path)?;
+        Ok(Self { path })
+    }
+}
+
+impl ShapeSprite for SvgPathShape {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        fill: Fill,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut buffers = Buffers::new();
+
+        self.tessellate(&self.path, &fill, &mut buffers, mode, tessellator);
+
+        create_sprite(material, meshes, buffers, transform)
+    }
+}
+
+/// A shape made of several independent contours, tessellated together into a
+/// single mesh.
+///
+/// Each contour is a list of points plus whether it should be closed. When
+/// contours overlap, which ones end up filled or left as holes depends on
+/// their winding order and the active fill rule — use this to build shapes
+/// like rings, donuts, or letterforms without stacking multiple entities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompoundShape {
+    pub contours: Vec<(Vec<Vec2>, bool)>,
+}
+
+impl Default for CompoundShape {
+    fn default() -> Self {
+        Self {
+            contours: Vec::new(),
+        }
+    }
+}
+
+impl ShapeSprite for CompoundShape {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        fill: Fill,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut buffers = Buffers::new();
+
+        let mut path_builder = Builder::new();
+        for (points, closed) in &self.contours {
+            let points = points.iter().map(|p| p.to_lyon_point()).collect::<Vec<Point>>();
+            let polygon = LyonPolygon {
+                points: points.as_slice(),
+                closed: *closed,
+            };
+            path_builder.add_polygon(polygon);
+        }
+        let path = path_builder.build();
+
+        self.tessellate(&path, &fill, &mut buffers, mode, tessellator);
 
         create_sprite(material, meshes, buffers, transform)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radius_feature_is_identity() {
+        assert_eq!(RegularPolygonFeature::Radius(2.5).radius(6), 2.5);
+    }
+
+    #[test]
+    fn apothem_feature_converts_to_circumradius() {
+        // A square (4 sides) with apothem 1 has a circumradius of sqrt(2).
+        let radius = RegularPolygonFeature::Apothem(1.0).radius(4);
+        assert!((radius - std::f32::consts::SQRT_2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn side_length_feature_converts_to_circumradius() {
+        // A regular hexagon's circumradius equals its side length.
+        let radius = RegularPolygonFeature::SideLength(3.0).radius(6);
+        assert!((radius - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn apothem_and_side_length_agree_for_the_same_polygon() {
+        let sides = 5;
+        let apothem = 1.0;
+        let apothem_radius = RegularPolygonFeature::Apothem(apothem).radius(sides);
+        let side_length = 2.0 * apothem * (PI / sides as f32).tan();
+        let side_length_radius = RegularPolygonFeature::SideLength(side_length).radius(sides);
+        assert!((apothem_radius - side_length_radius).abs() < 1e-5);
+    }
 }
\ No newline at end of file